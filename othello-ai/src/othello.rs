@@ -1,5 +1,9 @@
+mod bitboard;
 mod game;
 mod play;
 
-pub use game::{Cell, Game, Player};
-pub use play::{new_play, Play};
+pub use bitboard::Bitboard;
+pub use game::{
+    game_from_transcript, moves_to_transcript, Cell, Game, GameState, ParseGameError, Player,
+};
+pub use play::{new_play, play_from_algebraic, play_to_algebraic, Play};
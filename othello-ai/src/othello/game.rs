@@ -1,7 +1,61 @@
-use crate::othello::play::{new_play, Play};
+use crate::othello::bitboard::Bitboard;
+use crate::othello::play::{new_play, play_from_algebraic, play_to_algebraic, Play};
+use std::sync::OnceLock;
+
+/// Random keys for incremental Zobrist hashing: one pair per square (Black's
+/// key and White's key) plus one for the side to move. Generated once,
+/// lazily, the first time a hash is needed.
+struct ZobristKeys {
+    squares: [[u64; 2]; 64],
+    side: u64,
+}
+
+static ZOBRIST_KEYS: OnceLock<ZobristKeys> = OnceLock::new();
+
+fn zobrist_keys() -> &'static ZobristKeys {
+    ZOBRIST_KEYS.get_or_init(|| {
+        // splitmix64: not cryptographic, just a cheap, dependency-free source
+        // of well-distributed constants with a fixed seed so hashes are
+        // reproducible from run to run.
+        let mut state = 0x9E3779B97F4A7C15u64;
+        let mut next_key = || {
+            state = state.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        };
+
+        let mut squares = [[0u64; 2]; 64];
+        for slot in squares.iter_mut() {
+            *slot = [next_key(), next_key()];
+        }
+
+        ZobristKeys {
+            squares,
+            side: next_key(),
+        }
+    })
+}
+
+/// The Zobrist key for `player` occupying `square`.
+fn zobrist_square_key(square: u8, player: Player) -> u64 {
+    let color = if player == Player::Black { 0 } else { 1 };
+    zobrist_keys().squares[square as usize][color]
+}
 
-/// Alias for `u64`. A `BitField` is used for black locations and another for white locations.
-type BitField = u64;
+/// Error returned when a board string passed to `Game::from_str` isn't
+/// exactly 64 `-`/`B`/`W` cells followed by a `B`/`W` side-to-move marker.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseGameError;
+
+impl fmt::Display for ParseGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid board string")
+    }
+}
+
+impl std::error::Error for ParseGameError {}
 
 /// Represents the state of a cell
 #[derive(Debug, PartialEq)]
@@ -11,43 +65,85 @@ pub enum Cell {
     White,
 }
 
-/// Represents the current state of the game.
+/// One of the two sides playing the game.
 #[derive(Debug, PartialEq, Copy, Clone)]
 #[repr(i32)]
 pub enum Player {
     Black,
     White,
+}
+
+/// The outcome of a game, as computed by `Game::game_state`: still being
+/// played, or decided by final disc count once neither side has a move left.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum GameState {
     InProgress,
-    Tie,
+    BlackWins,
+    WhiteWins,
+    Draw,
 }
 
 /// Represents an Othello game board.
 #[derive(Debug, Clone)]
 pub struct Game {
-    pub black_pieces: BitField,
-    pub white_pieces: BitField,
+    pub black_pieces: Bitboard,
+    pub white_pieces: Bitboard,
 
     /// Next player to move
     pub player_to_move: Player,
     pub previous_move: Play,
+
+    /// Incremental Zobrist hash of the position, maintained by `play`.
+    /// Positions reached by different move orders hash equal, which is what
+    /// lets a `TranspositionTable` recognise them as the same node.
+    pub hash: u64,
 }
 
 impl Game {
     /// Creates a new blank game board.
     pub fn new() -> Self {
-        Self {
-            black_pieces: (1 << new_play(3, 3)) | (1 << new_play(4, 4)),
-            white_pieces: (1 << new_play(3, 4)) | (1 << new_play(4, 3)),
+        let mut game = Self {
+            black_pieces: Bitboard(1 << new_play(3, 3)) | Bitboard(1 << new_play(4, 4)),
+            white_pieces: Bitboard(1 << new_play(3, 4)) | Bitboard(1 << new_play(4, 3)),
             player_to_move: Player::Black,
-            previous_move: 0,
+            previous_move: 64,
+            hash: 0,
+        };
+        game.hash = game.compute_hash();
+        game
+    }
+
+    /// Recomputes the Zobrist hash from scratch: each occupied square's key
+    /// (chosen by the color occupying it) XORed together, plus the
+    /// side-to-move key when White is to move. `play` updates `hash`
+    /// incrementally instead of calling this on every move, so this exists
+    /// to initialize `hash` and to verify those incremental updates.
+    fn compute_hash(&self) -> u64 {
+        let mut hash = 0u64;
+        for square in self.black_pieces {
+            hash ^= zobrist_square_key(square, Player::Black);
+        }
+        for square in self.white_pieces {
+            hash ^= zobrist_square_key(square, Player::White);
         }
+        if self.player_to_move == Player::White {
+            hash ^= zobrist_keys().side;
+        }
+        hash
     }
 
     /// Shifts `disks` in the specified `Direction`.
+    ///
+    /// Relies on `Bitboard`'s LSB=upper-left, row-major bit layout: directions
+    /// `0..4` shift toward decreasing bit index (`Shr`, i.e. left/up on the
+    /// board) and `4..8` toward increasing bit index (`Shl`, i.e. right/down),
+    /// with `MASKS` clearing the squares that would otherwise wrap across the
+    /// left/right board edge. Each `MASKS` label names the direction rotated
+    /// 180 degrees from the shift's effect on the board.
     /// # Arguments
-    /// * `disks` - The `BitField` to shift
-    /// * `dir` - The `Direction` to shift the `BitField`
-    fn shift(disks: &BitField, dir: u8) -> BitField {
+    /// * `disks` - The `Bitboard` to shift
+    /// * `dir` - The `Direction` to shift the `Bitboard`
+    fn shift(disks: Bitboard, dir: u8) -> Bitboard {
         const MASKS: [u64; 8] = [
             0x7F7F7F7F7F7F7F7F, // Direction::Right
             0x007F7F7F7F7F7F7F, // Direction::DownRight
@@ -59,40 +155,41 @@ impl Game {
             0x7F7F7F7F7F7F7F00, // Direction::UpRight
         ];
 
-        const LSHIFTS: [u64; 8] = [0, 0, 0, 0, 1, 9, 8, 7];
-        const RSHIFTS: [u64; 8] = [1, 9, 8, 7, 0, 0, 0, 0];
+        const LSHIFTS: [u32; 8] = [0, 0, 0, 0, 1, 9, 8, 7];
+        const RSHIFTS: [u32; 8] = [1, 9, 8, 7, 0, 0, 0, 0];
 
         let dir_size = dir as usize;
         if dir < 4 {
             // shift right
             debug_assert!(LSHIFTS[dir_size] == 0, "Shifting right.");
-            (disks >> RSHIFTS[dir_size]) & MASKS[dir_size]
+            (disks >> RSHIFTS[dir_size]) & Bitboard(MASKS[dir_size])
         } else {
             // shift left
             debug_assert!(RSHIFTS[dir_size] == 0, "Shifting left.");
-            (disks << LSHIFTS[dir_size]) & MASKS[dir_size]
+            (disks << LSHIFTS[dir_size]) & Bitboard(MASKS[dir_size])
         }
     }
 
-    /// Returns a vector of moves. Generates moves for the player in `self.player_to_move`.
-    fn generate_plays_bitfield(&self) -> BitField {
-        let my_disks: &BitField;
-        let opponent_disks: &BitField;
-        if self.player_to_move == Player::Black {
-            my_disks = &self.black_pieces;
-            opponent_disks = &self.white_pieces;
-        } else {
-            my_disks = &self.white_pieces;
-            opponent_disks = &self.black_pieces;
-        }
+    /// Returns a bitboard of moves. Generates moves for the player in `self.player_to_move`.
+    pub(crate) fn generate_plays_bitfield(&self) -> Bitboard {
+        self.plays_bitfield_for(self.player_to_move)
+    }
 
-        let mut x: BitField;
+    /// Returns a bitboard of moves available to `player`, regardless of whose
+    /// turn it actually is. Used to compare both sides' mobility for
+    /// evaluation without having to construct a hypothetical `Game`.
+    pub(crate) fn plays_bitfield_for(&self, player: Player) -> Bitboard {
+        let (my_disks, opponent_disks) = if player == Player::Black {
+            (self.black_pieces, self.white_pieces)
+        } else {
+            (self.white_pieces, self.black_pieces)
+        };
 
-        let empty_cells: BitField = !(my_disks | opponent_disks); // opposite of union of my_disks and opponent_disks
-        let mut legal_moves: BitField = 0; // initially has no moves
+        let empty_cells = !(my_disks | opponent_disks); // opposite of union of my_disks and opponent_disks
+        let mut legal_moves = Bitboard::EMPTY; // initially has no moves
 
         debug_assert!(
-            self.black_pieces & self.white_pieces == 0,
+            (self.black_pieces & self.white_pieces).is_empty(),
             "Disk sets should be disjoint."
         );
 
@@ -100,21 +197,21 @@ impl Game {
             // perform 7 shifts in each direction and follow connected disks
 
             // get adjacent opponent disks
-            x = Self::shift(my_disks, dir) & opponent_disks;
+            let mut x = Self::shift(my_disks, dir) & opponent_disks;
 
             // add opponent disks adjacent to those
-            x |= Self::shift(&x, dir) & opponent_disks;
-            x |= Self::shift(&x, dir) & opponent_disks;
-            x |= Self::shift(&x, dir) & opponent_disks;
-            x |= Self::shift(&x, dir) & opponent_disks;
-            x |= Self::shift(&x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
 
             // empty cells adjacent to those are legal moves
-            legal_moves |= Self::shift(&x, dir) & empty_cells;
+            legal_moves |= Self::shift(x, dir) & empty_cells;
         }
 
         debug_assert!(
-            legal_moves & (self.black_pieces | self.white_pieces) == 0,
+            (legal_moves & (self.black_pieces | self.white_pieces)).is_empty(),
             "Legal moves should not be on black or white pieces."
         );
 
@@ -125,142 +222,229 @@ impl Game {
     /// # Postcondition
     /// The returned vector always has a least 1 play. If there are no plays available, the method returns the "skip" play (represented by 64).
     pub fn generate_plays(&self) -> Vec<Play> {
-        let mut bitfield: BitField = self.generate_plays_bitfield();
+        let mut plays: Vec<Play> = self.generate_plays_bitfield().collect();
 
-        let mut vec = Vec::new();
-        vec.reserve(20);
-        let mut index: u8 = 0;
-
-        while bitfield != 0 {
-            if bitfield % 2 == 1 {
-                vec.push(index);
-            }
-            bitfield >>= 1;
-            index += 1;
-        }
-
-        if vec.is_empty() {
+        if plays.is_empty() {
             // add "skip" Play
-            vec.push(64); // overflow
+            plays.push(64); // overflow
         }
 
-        debug_assert!(!vec.is_empty());
-        vec
+        debug_assert!(!plays.is_empty());
+        plays
     }
 
     // pub fn is_valid_move(&self) {}
 
-    /// Modifies game board and flips opponent disks.
-    fn resolve_play(&mut self, play: Play) {
-        let my_disks: &mut BitField;
-        let opponent_disks: &mut BitField;
-        if self.player_to_move == Player::Black {
-            my_disks = &mut self.black_pieces;
-            opponent_disks = &mut self.white_pieces;
-        } else {
-            my_disks = &mut self.white_pieces;
-            opponent_disks = &mut self.black_pieces;
+    /// Computes the captured-disk mask for playing `play` from this position,
+    /// or `None` if `play` is illegal: the target square is already occupied,
+    /// or flooding out from it in every direction captures no opponent disk.
+    /// The "skip" play (64) is the one exception, which is legal precisely
+    /// when the side to move has no other play and captures nothing.
+    fn captured_disks(&self, play: Play) -> Option<Bitboard> {
+        if play == 64 {
+            return if self.generate_plays_bitfield().is_empty() {
+                Some(Bitboard::EMPTY)
+            } else {
+                None
+            };
+        }
+        if play > 63 {
+            return None;
         }
 
-        let mut x: u64;
-
-        let new_disk: u64 = if play == 64 {
-            0 // error to overflow completely
+        let (my_disks, opponent_disks) = if self.player_to_move == Player::Black {
+            (self.black_pieces, self.white_pieces)
         } else {
-            1 << play // shift 1 to correct index
+            (self.white_pieces, self.black_pieces)
         };
-        let mut captured_disks: u64 = 0;
 
-        debug_assert!(play < 65, "Move must be within the board."); // 64 is "skip" turn
-        debug_assert!(
-            *my_disks & *opponent_disks == 0,
-            "Disk sets must be disjoint."
-        );
-        debug_assert!(
-            (*my_disks | *opponent_disks) & new_disk == 0,
-            "Target must be empty."
-        );
+        let new_disk = Bitboard(1 << play);
+        if !((my_disks | opponent_disks) & new_disk).is_empty() {
+            return None; // target square already occupied
+        }
 
-        *my_disks |= new_disk; // mutate my_disks
+        let mut captured_disks = Bitboard::EMPTY;
 
-        // flip opponent_disks
         for dir in 0..8 {
             // find opponent disk adjacent to new_disk
-            x = Self::shift(&new_disk, dir) & *opponent_disks;
+            let mut x = Self::shift(new_disk, dir) & opponent_disks;
             // follow adjacent disks
-            x |= Self::shift(&x, dir) & *opponent_disks;
-            x |= Self::shift(&x, dir) & *opponent_disks;
-            x |= Self::shift(&x, dir) & *opponent_disks;
-            x |= Self::shift(&x, dir) & *opponent_disks;
-            x |= Self::shift(&x, dir) & *opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
+            x |= Self::shift(x, dir) & opponent_disks;
 
             // determine whether the disks were captured
-            let bounding_disk = Self::shift(&x, dir) & *my_disks;
-            captured_disks |= if bounding_disk != 0 { x } else { 0 }; // do nothing if bounding_disk == 0
+            let bounding_disk = Self::shift(x, dir) & my_disks;
+            if !bounding_disk.is_empty() {
+                captured_disks |= x; // do nothing if bounding_disk is empty
+            }
         }
 
-        // mutate board with captured_disks
-        *my_disks ^= captured_disks;
-        *opponent_disks ^= captured_disks;
+        if captured_disks.is_empty() {
+            None
+        } else {
+            Some(captured_disks)
+        }
+    }
+
+    /// Returns the position after playing `play`, or `None` if `play` is not
+    /// legal for `self.player_to_move`. `self` is left untouched either way,
+    /// so callers such as MCTS node expansion can cheaply try candidate plays
+    /// without cloning and asserting.
+    pub fn play(&self, play: Play) -> Option<Game> {
+        let captured_disks = self.captured_disks(play)?;
+        let new_disk = if play == 64 {
+            Bitboard::EMPTY
+        } else {
+            Bitboard(1 << play)
+        };
 
-        // flip player_to_move
-        self.player_to_move = if self.player_to_move == Player::Black {
+        let my_player = self.player_to_move;
+        let opponent_player = if my_player == Player::Black {
             Player::White
         } else {
             Player::Black
         };
 
-        debug_assert!(
-            (*my_disks & *opponent_disks) == 0,
-            "Disk sets must still be disjoint"
+        let mut next = self.clone();
+        {
+            let (my_disks, opponent_disks) = if my_player == Player::Black {
+                (&mut next.black_pieces, &mut next.white_pieces)
+            } else {
+                (&mut next.white_pieces, &mut next.black_pieces)
+            };
+
+            *my_disks |= new_disk;
+            *my_disks ^= captured_disks;
+            *opponent_disks ^= captured_disks;
+        }
+
+        // Update the hash incrementally: XOR in the newly placed disk (the
+        // skip play places nothing), then for each flipped disk XOR out the
+        // opponent's key and XOR in ours, then toggle the side-to-move key.
+        if play != 64 {
+            next.hash ^= zobrist_square_key(play, my_player);
+        }
+        for square in captured_disks {
+            next.hash ^= zobrist_square_key(square, opponent_player);
+            next.hash ^= zobrist_square_key(square, my_player);
+        }
+        next.hash ^= zobrist_keys().side;
+
+        next.player_to_move = opponent_player;
+        next.previous_move = play;
+
+        debug_assert_eq!(
+            next.hash,
+            next.compute_hash(),
+            "incremental Zobrist hash update diverged from a from-scratch recompute"
         );
+
+        Some(next)
     }
 
-    /// Makes sure `play` is a valid `Play` and mutates the board.
+    /// Mutates the board by playing `play`.
+    /// # Panics
+    /// Panics if `play` is not legal for `self.player_to_move`. Prefer
+    /// [`Game::play`] to validate without risking a panic.
     pub fn make_play(&mut self, play: Play) {
-        // TODO: make sure play is valid
-        // debug_assert!
-        self.resolve_play(play);
-        self.previous_move = play;
+        *self = self.play(play).expect("play must be legal");
     }
 
+    /// Returns whether `play` is legal for `self.player_to_move`.
     pub fn is_valid_play(&self, play: Play) -> bool {
-        let plays = self.generate_plays_bitfield();
+        self.captured_disks(play).is_some()
+    }
 
-        let mask = 1 << play;
+    /// Returns whether `self.player_to_move` has any legal play other than
+    /// the "skip" play. When this is `false`, the side to move must pass.
+    pub fn has_valid_plays(&self) -> bool {
+        !self.generate_plays_bitfield().is_empty()
+    }
 
-        plays & mask != 0
+    /// Returns the position after `self.player_to_move` passes, or `None` if
+    /// they have a legal play and passing isn't allowed.
+    pub fn passed(&self) -> Option<Game> {
+        if self.has_valid_plays() {
+            None
+        } else {
+            self.play(64)
+        }
+    }
+
+    /// Mutates the board by passing.
+    /// # Panics
+    /// Panics if `self.player_to_move` has a legal play. Prefer
+    /// [`Game::passed`] to validate without risking a panic.
+    pub fn pass(&mut self) {
+        *self = self
+            .passed()
+            .expect("pass is only legal when has_valid_plays() is false");
     }
 
     /// Returns the `Cell` state with the specified `row` and `col`.
     pub fn cell_state(&self, row: u8, col: u8) -> Cell {
-        let mask: u64 = 1 << new_play(row, col);
+        let square = new_play(row, col);
 
-        if self.black_pieces & mask != 0 {
+        if self.black_pieces.contains(square) {
             Cell::Black
-        } else if self.white_pieces & mask != 0 {
+        } else if self.white_pieces.contains(square) {
             Cell::White
         } else {
             Cell::Empty
         }
     }
 
-    /// Computes the game state.
-    pub fn game_state(&self) -> Player {
-        if !(self.black_pieces | self.white_pieces) != 0 {
-            Player::InProgress
-        } else {
-            // count number of pieces of each color
-            let black_count = self.black_pieces.count_ones();
-            let white_count = self.white_pieces.count_ones();
-
-            match black_count.cmp(&white_count) {
-                Ordering::Less => Player::White,
-                Ordering::Equal => Player::Tie,
-                Ordering::Greater => Player::Black,
-            }
+    /// Computes the game state: `InProgress` unless neither player has a
+    /// legal play, which can happen before the board is full if both sides
+    /// are stuck passing to each other.
+    pub fn game_state(&self) -> GameState {
+        if self.has_valid_plays() {
+            return GameState::InProgress;
+        }
+
+        // `player_to_move` has no legal play: passing is forced.
+        let passed = self
+            .passed()
+            .expect("has_valid_plays() is false, so a pass is legal");
+        if passed.has_valid_plays() {
+            return GameState::InProgress; // the other side can still move
+        }
+
+        // Neither side can move: the game is over.
+        let black_count = self.black_pieces.count_ones();
+        let white_count = self.white_pieces.count_ones();
+
+        match black_count.cmp(&white_count) {
+            Ordering::Less => GameState::WhiteWins,
+            Ordering::Equal => GameState::Draw,
+            Ordering::Greater => GameState::BlackWins,
         }
     }
+
+    /// Serializes the board to a 65-character string: 64 `-`/`B`/`W` cells in
+    /// row-major order (the same layout as `Display`), followed by a `B` or
+    /// `W` marking `player_to_move`. Round-trips through `Game::from_str`.
+    pub fn to_string_board(&self) -> String {
+        let mut out = String::with_capacity(65);
+        for square in 0..64u8 {
+            out.push(if self.black_pieces.contains(square) {
+                'B'
+            } else if self.white_pieces.contains(square) {
+                'W'
+            } else {
+                '-'
+            });
+        }
+        out.push(if self.player_to_move == Player::Black {
+            'B'
+        } else {
+            'W'
+        });
+        out
+    }
 }
 
 impl Default for Game {
@@ -281,6 +465,84 @@ impl fmt::Display for Cell {
     }
 }
 
+impl std::str::FromStr for Game {
+    type Err = ParseGameError;
+
+    /// Parses the format produced by `Game::to_string_board`: 64 `-`/`B`/`W`
+    /// cells followed by a `B`/`W` side-to-move marker. `previous_move` isn't
+    /// encoded and is reset to the "skip" play (64).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        if chars.len() != 65 {
+            return Err(ParseGameError);
+        }
+
+        let mut black_pieces = Bitboard::EMPTY;
+        let mut white_pieces = Bitboard::EMPTY;
+        for (square, &cell) in chars[..64].iter().enumerate() {
+            let square = square as u8;
+            match cell {
+                'B' => black_pieces |= Bitboard(1 << square),
+                'W' => white_pieces |= Bitboard(1 << square),
+                '-' => {}
+                _ => return Err(ParseGameError),
+            }
+        }
+
+        let player_to_move = match chars[64] {
+            'B' => Player::Black,
+            'W' => Player::White,
+            _ => return Err(ParseGameError),
+        };
+
+        let mut game = Game {
+            black_pieces,
+            white_pieces,
+            player_to_move,
+            previous_move: 64,
+            hash: 0,
+        };
+        game.hash = game.compute_hash();
+        Ok(game)
+    }
+}
+
+/// Formats `plays` as a whitespace-separated transcript of algebraic square
+/// names (e.g. `"d3 c4 f5"`), suitable for `game_from_transcript` to replay.
+/// Skip plays (64) are omitted rather than written out, since
+/// `game_from_transcript` already auto-inserts a pass wherever the side to
+/// move has no legal play.
+pub fn moves_to_transcript(plays: &[Play]) -> String {
+    plays
+        .iter()
+        .filter(|&&play| play != 64)
+        .map(|&play| play_to_algebraic(play))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Replays a whitespace-separated transcript of algebraic squares (as
+/// produced by `moves_to_transcript`) from `Game::new()`, auto-inserting a
+/// pass whenever the side to move has no legal play before the transcript's
+/// next move. Returns `None` if a square is malformed or the move it names
+/// isn't legal once any forced passes are played.
+pub fn game_from_transcript(transcript: &str) -> Option<Game> {
+    let mut game = Game::new();
+    for token in transcript.split_whitespace() {
+        let play = play_from_algebraic(token)?;
+        while !game.has_valid_plays() {
+            // Neither side can move: the game is already over, so a token
+            // after this point can never be legal.
+            if game.game_state() != GameState::InProgress {
+                return None;
+            }
+            game = game.passed()?;
+        }
+        game = game.play(play)?;
+    }
+    Some(game)
+}
+
 impl fmt::Display for Game {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for row in 0..8 {
@@ -1,13 +1,40 @@
-/// Represents the position on the game board.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Play(pub u8);
+/// Represents the position on the game board: a bit index `0..64` (row-major,
+/// matching `Bitboard`'s layout), or `64` for the "skip" play.
+pub type Play = u8;
 
-impl Play {
-    /// Create a new `Play` with specified `row` and `col`.
-    pub fn new(row: u8, col: u8) -> Self {
-        debug_assert!(row < 8);
-        debug_assert!(col < 8);
+/// Creates a new `Play` from the specified `row` and `col`.
+pub fn new_play(row: u8, col: u8) -> Play {
+    debug_assert!(row < 8);
+    debug_assert!(col < 8);
 
-        Self(row * 8 + col)
+    row * 8 + col
+}
+
+/// Parses a square in standard Othello algebraic notation (column `a`-`h`,
+/// row `1`-`8`, e.g. `"d3"`) into a `Play`. Returns `None` if `text` isn't a
+/// well-formed square.
+pub fn play_from_algebraic(text: &str) -> Option<Play> {
+    let mut chars = text.chars();
+    let col = chars.next()?;
+    let row = chars.next()?;
+    if chars.next().is_some() {
+        return None; // trailing characters
+    }
+    if !('a'..='h').contains(&col) || !('1'..='8').contains(&row) {
+        return None;
+    }
+
+    Some(new_play(row as u8 - b'1', col as u8 - b'a'))
+}
+
+/// Formats `play` in standard Othello algebraic notation (e.g. `"c4"`), or
+/// `"--"` for the "skip" play.
+pub fn play_to_algebraic(play: Play) -> String {
+    if play == 64 {
+        return "--".to_string();
     }
+
+    let row = play / 8;
+    let col = play % 8;
+    format!("{}{}", (b'a' + col) as char, row + 1)
 }
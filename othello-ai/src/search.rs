@@ -0,0 +1,385 @@
+use crate::othello::{Game, Play, Player};
+use std::collections::HashMap;
+
+/// Number of remaining empty squares at or below which the exact solver
+/// takes over from the heuristic search: few enough empties that a
+/// full-depth search is fast and provably optimal.
+const ENDGAME_THRESHOLD: u32 = 12;
+
+/// Positional weight of each square, row-major to match `Bitboard`'s layout.
+/// Corners are very strong since they can never be flipped; the squares
+/// diagonally and orthogonally adjacent to a corner (the "X" and "C"
+/// squares) are penalised because playing them tends to hand the corner to
+/// the opponent.
+#[rustfmt::skip]
+const POSITION_WEIGHTS: [i32; 64] = [
+    100, -20,  10,   5,   5,  10, -20, 100,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+     10,  -2,   5,   1,   1,   5,  -2,  10,
+      5,  -2,   1,   1,   1,   1,  -2,   5,
+      5,  -2,   1,   1,   1,   1,  -2,   5,
+     10,  -2,   5,   1,   1,   5,  -2,  10,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+    100, -20,  10,   5,   5,  10, -20, 100,
+];
+
+/// Number of empty squares remaining on `game`'s board.
+fn empties(game: &Game) -> u32 {
+    64 - (game.black_pieces | game.white_pieces).count_ones()
+}
+
+/// Heuristic evaluation of `game` from Black's perspective: positive favours
+/// Black, negative favours White. Blends weighted disc placement, mobility
+/// difference, and disc-count parity, shifting weight from the first two
+/// toward raw parity as the board fills, since placement and mobility matter
+/// less once there is little room left to manoeuvre.
+fn evaluate(game: &Game) -> i32 {
+    let mut positional = 0i32;
+    for square in 0..64u8 {
+        if game.black_pieces.contains(square) {
+            positional += POSITION_WEIGHTS[square as usize];
+        } else if game.white_pieces.contains(square) {
+            positional -= POSITION_WEIGHTS[square as usize];
+        }
+    }
+
+    let black_mobility = game.plays_bitfield_for(Player::Black).count_ones() as i32;
+    let white_mobility = game.plays_bitfield_for(Player::White).count_ones() as i32;
+    let mobility = black_mobility - white_mobility;
+
+    let disc_parity = game.black_pieces.count_ones() as i32 - game.white_pieces.count_ones() as i32;
+
+    let phase = (64 - empties(game)) as f32 / 64.0; // 0 at the opening, 1 once full
+    let positional_score = positional as f32 * (1.0 - phase);
+    let mobility_score = mobility as f32 * 10.0 * (1.0 - phase);
+    let parity_score = disc_parity as f32 * 10.0 * phase;
+
+    (positional_score + mobility_score + parity_score) as i32
+}
+
+/// `evaluate`, reoriented to `game.player_to_move`'s perspective for use as a
+/// negamax leaf score.
+fn side_to_move_eval(game: &Game) -> i32 {
+    let score = evaluate(game);
+    if game.player_to_move == Player::Black {
+        score
+    } else {
+        -score
+    }
+}
+
+/// Final disc differential `black - white` from the side-to-move's perspective.
+fn disc_differential(game: &Game) -> i32 {
+    let diff = game.black_pieces.count_ones() as i32 - game.white_pieces.count_ones() as i32;
+    if game.player_to_move == Player::Black {
+        diff
+    } else {
+        -diff
+    }
+}
+
+/// Score for a position that is genuinely game-over (both sides passed),
+/// scaled into a band that dominates every possible `evaluate` score. Without
+/// this, a proven endgame result (a disc differential of at most ±64) could
+/// rank below an inflated heuristic estimate from a sibling line that bottoms
+/// out at `depth == 0` instead, letting the heuristic override a proven win
+/// or loss.
+const WIN_SCORE_SCALE: i32 = 1_000_000;
+
+fn terminal_score(game: &Game) -> i32 {
+    disc_differential(game) * WIN_SCORE_SCALE
+}
+
+/// Fail-soft alpha-beta negamax search to `depth` plies, returning the score
+/// from `game.player_to_move`'s perspective. Switches to the exact endgame
+/// solver once few empty squares remain, regardless of `depth`.
+pub fn negamax(game: &Game, depth: u8, alpha: i32, beta: i32) -> i32 {
+    negamax_with_pass(game, depth, alpha, beta, false)
+}
+
+/// `negamax`'s implementation, tracking whether the previous ply was a pass
+/// so that two consecutive passes terminate the node instead of looping
+/// forever without consuming `depth`.
+fn negamax_with_pass(game: &Game, depth: u8, mut alpha: i32, beta: i32, passed: bool) -> i32 {
+    if empties(game) <= ENDGAME_THRESHOLD {
+        return solve_exact(game, alpha, beta, passed);
+    }
+
+    let plays = game.generate_plays();
+    if plays.len() == 1 && plays[0] == 64 {
+        if passed {
+            // Neither side can move: the position is terminal.
+            return terminal_score(game);
+        }
+        let next = game
+            .play(64)
+            .expect("the skip play is always legal when generate_plays is just [64]");
+        return -negamax_with_pass(&next, depth, -beta, -alpha, true);
+    }
+
+    if depth == 0 {
+        return side_to_move_eval(game);
+    }
+
+    let mut best_score = i32::MIN;
+    for play in plays {
+        let child = game
+            .play(play)
+            .expect("play came from generate_plays and is always legal");
+
+        let score = -negamax_with_pass(&child, depth - 1, -beta, -alpha, false);
+        if score > best_score {
+            best_score = score;
+        }
+        if best_score >= beta {
+            break; // prune: this node is already good enough to be cut off
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    best_score
+}
+
+/// Exact full-depth negamax used once few empty squares remain: recurses all
+/// the way to a genuinely terminal position and scores it by the final disc
+/// differential rather than `evaluate`'s heuristic.
+fn solve_exact(game: &Game, mut alpha: i32, beta: i32, passed: bool) -> i32 {
+    let plays = game.generate_plays();
+    if plays.len() == 1 && plays[0] == 64 {
+        if passed {
+            return terminal_score(game);
+        }
+        let next = game
+            .play(64)
+            .expect("the skip play is always legal when generate_plays is just [64]");
+        return -solve_exact(&next, -beta, -alpha, true);
+    }
+
+    let mut best_score = i32::MIN;
+    for play in plays {
+        let child = game
+            .play(play)
+            .expect("play came from generate_plays and is always legal");
+
+        let score = -solve_exact(&child, -beta, -alpha, false);
+        if score > best_score {
+            best_score = score;
+        }
+        if best_score >= beta {
+            break;
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    best_score
+}
+
+/// Returns the best play `negamax` finds for `game`, searching `depth` plies
+/// (ignored once the exact endgame solver takes over, where the result is
+/// provably optimal; above that, it is only as good as `evaluate`). Unlike
+/// the MCTS agent, this is deterministic for a given `game` and `depth`.
+pub fn best_play(game: &Game, depth: u8) -> Play {
+    let plays = game.generate_plays();
+    if plays.len() == 1 {
+        return plays[0];
+    }
+
+    let mut best_score = i32::MIN;
+    let mut best_play = plays[0];
+    let mut alpha = i32::MIN + 1; // +1 so `-alpha` never overflows
+    let beta = i32::MAX;
+
+    for play in plays {
+        let child = game
+            .play(play)
+            .expect("play came from generate_plays and is always legal");
+
+        let score = -negamax(&child, depth.saturating_sub(1), -beta, -alpha);
+        if score > best_score {
+            best_score = score;
+            best_play = play;
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    best_play
+}
+
+/// Which bound a `TranspositionEntry`'s `score` represents, following the
+/// usual fail-soft alpha-beta convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bound {
+    /// `score` is the position's exact value: the full window was searched.
+    Exact,
+    /// A beta cutoff occurred; the true value is at least `score`.
+    Lower,
+    /// Every move scored below `alpha`; the true value is at most `score`.
+    Upper,
+}
+
+/// A cached `negamax` result for one position.
+#[derive(Debug, Clone, Copy)]
+struct TranspositionEntry {
+    depth: u8,
+    score: i32,
+    bound: Bound,
+    best_play: Play,
+}
+
+/// Transposition table keyed by `Game::hash`, shared by every
+/// `negamax_with_tt` call it is passed to: positions reached by different
+/// move orders, within one search or across searches, hash to the same
+/// entry, letting a later visit reuse or tighten the bounds an earlier visit
+/// already computed instead of re-searching from scratch.
+#[derive(Debug, Default)]
+pub struct TranspositionTable {
+    entries: HashMap<u64, TranspositionEntry>,
+}
+
+impl TranspositionTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a usable score for `(game, depth, alpha, beta)` if a prior,
+    /// at-least-as-deep visit already settled it, `None` otherwise.
+    fn probe(&self, game: &Game, depth: u8, alpha: i32, beta: i32) -> Option<i32> {
+        let entry = self.entries.get(&game.hash)?;
+        if entry.depth < depth {
+            return None; // not searched deep enough to trust
+        }
+        match entry.bound {
+            Bound::Exact => Some(entry.score),
+            Bound::Lower if entry.score >= beta => Some(entry.score),
+            Bound::Upper if entry.score <= alpha => Some(entry.score),
+            _ => None,
+        }
+    }
+
+    fn store(&mut self, game: &Game, depth: u8, score: i32, bound: Bound, best_play: Play) {
+        self.entries.insert(
+            game.hash,
+            TranspositionEntry {
+                depth,
+                score,
+                bound,
+                best_play,
+            },
+        );
+    }
+}
+
+/// `negamax`, augmented with a `TranspositionTable` so that transposed
+/// positions reuse or tighten bounds from earlier in the same search rather
+/// than being re-searched from scratch.
+pub fn negamax_with_tt(
+    game: &Game,
+    depth: u8,
+    alpha: i32,
+    beta: i32,
+    tt: &mut TranspositionTable,
+) -> i32 {
+    negamax_with_tt_and_pass(game, depth, alpha, beta, false, tt)
+}
+
+fn negamax_with_tt_and_pass(
+    game: &Game,
+    depth: u8,
+    mut alpha: i32,
+    beta: i32,
+    passed: bool,
+    tt: &mut TranspositionTable,
+) -> i32 {
+    if empties(game) <= ENDGAME_THRESHOLD {
+        return solve_exact(game, alpha, beta, passed);
+    }
+
+    let original_alpha = alpha;
+    if let Some(score) = tt.probe(game, depth, alpha, beta) {
+        return score;
+    }
+
+    let plays = game.generate_plays();
+    if plays.len() == 1 && plays[0] == 64 {
+        if passed {
+            return terminal_score(game);
+        }
+        let next = game
+            .play(64)
+            .expect("the skip play is always legal when generate_plays is just [64]");
+        return -negamax_with_tt_and_pass(&next, depth, -beta, -alpha, true, tt);
+    }
+
+    if depth == 0 {
+        return side_to_move_eval(game);
+    }
+
+    let mut best_score = i32::MIN;
+    let mut best_play = plays[0];
+    for play in plays {
+        let child = game
+            .play(play)
+            .expect("play came from generate_plays and is always legal");
+
+        let score = -negamax_with_tt_and_pass(&child, depth - 1, -beta, -alpha, false, tt);
+        if score > best_score {
+            best_score = score;
+            best_play = play;
+        }
+        if best_score >= beta {
+            break; // prune: this node is already good enough to be cut off
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    let bound = if best_score <= original_alpha {
+        Bound::Upper
+    } else if best_score >= beta {
+        Bound::Lower
+    } else {
+        Bound::Exact
+    };
+    tt.store(game, depth, best_score, bound, best_play);
+
+    best_score
+}
+
+/// `best_play`, backed by a caller-supplied `TranspositionTable` so repeated
+/// calls (e.g. turn after turn across a game) can keep reusing entries from
+/// positions already seen.
+pub fn best_play_with_tt(game: &Game, depth: u8, tt: &mut TranspositionTable) -> Play {
+    let plays = game.generate_plays();
+    if plays.len() == 1 {
+        return plays[0];
+    }
+
+    let mut best_score = i32::MIN;
+    let mut best_play = plays[0];
+    let mut alpha = i32::MIN + 1; // +1 so `-alpha` never overflows
+    let beta = i32::MAX;
+
+    for play in plays {
+        let child = game
+            .play(play)
+            .expect("play came from generate_plays and is always legal");
+
+        let score = -negamax_with_tt(&child, depth.saturating_sub(1), -beta, -alpha, tt);
+        if score > best_score {
+            best_score = score;
+            best_play = play;
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    best_play
+}
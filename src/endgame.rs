@@ -0,0 +1,113 @@
+use crate::othello::{Game, Play, Player};
+
+/// Number of remaining empty squares at or below which the exact solver takes
+/// over from MCTS. This solver clones the board at every node and has no
+/// transposition table or move ordering, so it only stays within a search
+/// budget up to roughly this many empties; engines that solve deeper (~14)
+/// rely on a TT plus ordering that this one doesn't have.
+const THRESHOLD: u32 = 10;
+
+/// Returns `true` when `game` has few enough empty squares that the exact solver
+/// should be used instead of the MCTS estimate.
+pub fn is_solvable(game: &Game) -> bool {
+    (game.black_pieces | game.white_pieces).count_ones() >= 64 - THRESHOLD
+}
+
+/// Solves `game` exactly and returns the provably optimal `Play`, or the "skip"
+/// play (64) when the side to move has no legal move.
+pub fn solve(game: &Game) -> Play {
+    let moves = game.generate_plays_bitfield();
+    if moves == 0 {
+        return 64; // must pass
+    }
+
+    let mut best_score = i32::MIN;
+    let mut best_play: Play = 64;
+    let mut alpha = i32::MIN + 1; // +1 so `-alpha` never overflows
+    let beta = i32::MAX;
+
+    for play in SetBits(moves) {
+        let mut child = game.clone();
+        child
+            .make_play(play)
+            .expect("play came from generate_plays_bitfield and is always legal");
+
+        let score = -negamax(&child, -beta, -alpha, false);
+        if score > best_score {
+            best_score = score;
+            best_play = play;
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    best_play
+}
+
+/// Negamax alpha-beta search scoring `game` from the side-to-move's perspective.
+/// `passed` records whether the previous ply was a pass, so that two consecutive
+/// passes terminate the node and score it by the final disc differential.
+fn negamax(game: &Game, mut alpha: i32, beta: i32, passed: bool) -> i32 {
+    let moves = game.generate_plays_bitfield();
+
+    if moves == 0 {
+        if passed {
+            // Neither side can move: the position is terminal.
+            return disc_differential(game);
+        }
+        // The side to move has no legal play and must pass.
+        let mut next = game.clone();
+        next.make_play(64)
+            .expect("the skip play is always legal when generate_plays_bitfield is empty");
+        return -negamax(&next, -beta, -alpha, true);
+    }
+
+    let mut best_score = i32::MIN;
+    for play in SetBits(moves) {
+        let mut child = game.clone();
+        child
+            .make_play(play)
+            .expect("play came from generate_plays_bitfield and is always legal");
+
+        let score = -negamax(&child, -beta, -alpha, false);
+        if score > best_score {
+            best_score = score;
+        }
+        if best_score >= beta {
+            break; // prune: this node is already good enough to be cut off
+        }
+        if best_score > alpha {
+            alpha = best_score;
+        }
+    }
+
+    best_score
+}
+
+/// Final disc differential `black - white` from the side-to-move's perspective.
+fn disc_differential(game: &Game) -> i32 {
+    let diff = game.black_pieces.count_ones() as i32 - game.white_pieces.count_ones() as i32;
+    if game.player_to_move == Player::Black {
+        diff
+    } else {
+        -diff
+    }
+}
+
+/// Iterates over the indices of the set bits of a `u64`, lowest first, using
+/// `trailing_zeros` and the `x &= x - 1` trick to clear each bit in turn.
+struct SetBits(u64);
+
+impl Iterator for SetBits {
+    type Item = Play;
+
+    fn next(&mut self) -> Option<Play> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros() as Play;
+        self.0 &= self.0 - 1;
+        Some(index)
+    }
+}
@@ -1,10 +1,11 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
+mod endgame;
 mod node;
 mod othello;
 
-use node::Mcts;
+use node::{Mcts, StopCondition};
 use othello::game::{Game, Player};
 use othello::play::Play;
 use rand::{thread_rng, Rng};
@@ -20,9 +21,15 @@ fn main() {
         let play = if game.player_to_move == Player::Black {
             // mcts ai
             let mut mcts_agent = Mcts::new(game.clone());
-            mcts_agent.run_search();
+            let search_res = mcts_agent
+                .run_search(StopCondition::TimeMs(100))
+                .expect("search should not fail here");
+            println!(
+                "{} games simulated, best play win rate {:.2}.",
+                search_res.search_iterations, search_res.best_play_win_rate
+            );
 
-            mcts_agent.best_play()
+            search_res.best_play
         } else {
             // random ai
             let plays = game.generate_plays();
@@ -31,7 +38,7 @@ fn main() {
 
             plays[rand_index]
         };
-        game.make_play(play);
+        game.make_play(play).expect("play is always legal here");
         println!("{}", game);
     }
 
@@ -1,8 +1,88 @@
-use crate::othello::{Game, Play, Player};
-use rand::prelude::*;
+use crate::othello::{Game, IllegalMove, Play, Player};
+use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
 
 const C_PARAM: f32 = 1.41; // sqrt(2)
 
+/// Error returned by search operations that cannot proceed: an exhausted
+/// expansion, a root that has not been fully expanded yet, or an externally
+/// supplied play that is not legal for the current position.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SearchError {
+    /// The node had no unexpanded moves left to expand.
+    NoMovesToExpand,
+    /// The root has not been fully expanded, so no play can be selected yet.
+    RootNotFullyExpanded,
+    /// An externally supplied play was not legal for the current position.
+    IllegalMove,
+}
+
+impl From<IllegalMove> for SearchError {
+    fn from(_: IllegalMove) -> Self {
+        SearchError::IllegalMove
+    }
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchError::NoMovesToExpand => write!(f, "node has no unexpanded moves left"),
+            SearchError::RootNotFullyExpanded => write!(f, "root is not fully expanded"),
+            SearchError::IllegalMove => write!(f, "play is not legal for the current position"),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+/// Key identifying a board position for the transposition table. The same
+/// `(black_pieces, white_pieces, player_to_move)` reached by different move
+/// orders maps to the same entry.
+type BoardKey = (u64, u64, bool);
+
+/// Default seed used by [`Mcts::new`] so that a freshly constructed search is
+/// reproducible unless the caller explicitly asks for another seed.
+const DEFAULT_SEED: [u8; 32] = [0; 32];
+
+/// Static positional weights used to bias rollout move selection. Corners are
+/// highly valuable, while the adjacent X- and C-squares are penalised because
+/// playing them tends to give the corner away. Row-major, matching the board
+/// bit layout (index = `row * 8 + col`).
+#[rustfmt::skip]
+const POSITION_WEIGHTS: [i32; 64] = [
+    120, -20,  20,   5,   5,  20, -20, 120,
+    -20, -40,  -5,  -5,  -5,  -5, -40, -20,
+     20,  -5,  15,   3,   3,  15,  -5,  20,
+      5,  -5,   3,   3,   3,   3,  -5,   5,
+      5,  -5,   3,   3,   3,   3,  -5,   5,
+     20,  -5,  15,   3,   3,  15,  -5,  20,
+    -20, -40,  -5,  -5,  -5,  -5, -40, -20,
+    120, -20,  20,   5,   5,  20, -20, 120,
+];
+
+/// Tree-selection policy used to trade off exploration and exploitation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SelectionPolicy {
+    /// Standard UCB1: `mean + C * sqrt(ln(parent) / child)`.
+    Ucb1,
+    /// UCB1-tuned, which replaces the constant `1/4` variance ceiling with the
+    /// empirical reward variance for a tighter exploration bound.
+    Ucb1Tuned,
+}
+
+/// Strategy used to pick moves during an MCTS rollout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RolloutPolicy {
+    /// Uniform random playout — the original behaviour, kept for comparison.
+    Uniform,
+    /// Softmax-weighted sampling biased by `POSITION_WEIGHTS` and mobility.
+    Heuristic,
+}
+
 /// Represents a node in the MCTS Tree.
 pub struct Node {
     /// The index of the parent node in the `MCTSTree.arena`.
@@ -21,11 +101,12 @@ pub struct Node {
 
 impl Node {
     /// Creates a new `Node` with the specified `Game` state. `children` is generated automatically from `state`.
-    pub fn new(state: Game, parent: Option<usize>) -> Self {
+    /// The move order is shuffled using `rng` so that expansion order is reproducible for a given seed.
+    pub fn new(state: Game, parent: Option<usize>, rng: &mut StdRng) -> Self {
         let mut plays = state.generate_plays();
 
         // shuffle plays
-        plays.shuffle(&mut thread_rng());
+        plays.shuffle(rng);
 
         Self {
             parent,
@@ -48,6 +129,76 @@ impl Node {
 
 pub struct MctsSearchResult {
     pub search_iterations: u32,
+    /// Wall-clock time actually spent searching, regardless of which
+    /// `StopCondition` ended the search.
+    pub elapsed: Duration,
+    /// The play `Mcts::best_play` would currently return.
+    pub best_play: Play,
+    /// Pooled visit count backing `best_play`.
+    pub best_play_visits: u32,
+    /// Pooled win count backing `best_play`.
+    pub best_play_wins: f32,
+    /// `best_play_wins / best_play_visits`, or `0.0` if `best_play_visits == 0`
+    /// (the exact-solver branch, where there is no MCTS estimate to report).
+    pub best_play_win_rate: f32,
+}
+
+/// Condition under which `Mcts::run_search` stops. `TimeMs` and `Iterations`
+/// cap wall-clock time or playout count respectively, so callers trade off
+/// deterministic benchmarks (`Iterations`) against a wall-clock budget
+/// (`TimeMs`); `OrElse` composes two conditions so the search stops as soon as
+/// either is satisfied.
+#[derive(Debug, Clone)]
+pub enum StopCondition {
+    /// Run until `ms` milliseconds have elapsed.
+    TimeMs(u128),
+    /// Run for at most `iterations` playouts (always at least one), stopping
+    /// earlier if the root's leading child can no longer be overtaken.
+    Iterations(u32),
+    /// Stop as soon as either sub-condition is satisfied.
+    OrElse(Box<StopCondition>, Box<StopCondition>),
+}
+
+impl StopCondition {
+    /// Whether the search should stop, having already run `iterations`
+    /// playouts over `elapsed` wall-clock time.
+    fn is_met(&self, iterations: u32, elapsed: Duration) -> bool {
+        match self {
+            StopCondition::TimeMs(ms) => elapsed.as_millis() > *ms,
+            StopCondition::Iterations(n) => iterations >= *n,
+            StopCondition::OrElse(a, b) => a.is_met(iterations, elapsed) || b.is_met(iterations, elapsed),
+        }
+    }
+
+    /// Upper bound on how many further playouts this condition could still
+    /// allow, having already run `iterations` over `elapsed`. `TimeMs`
+    /// estimates this from the average iteration rate observed so far, so it
+    /// returns `None` until at least one iteration has completed. Used only
+    /// for the early-exit decisiveness check, not to decide when to stop.
+    fn remaining_iterations_bound(&self, iterations: u32, elapsed: Duration) -> Option<u32> {
+        match self {
+            StopCondition::Iterations(n) => Some(n.saturating_sub(iterations)),
+            StopCondition::TimeMs(ms) => {
+                if iterations == 0 || elapsed.as_millis() == 0 {
+                    return None;
+                }
+                let rate = iterations as f64 / elapsed.as_millis() as f64; // iterations per ms
+                let remaining_ms = (*ms as f64 - elapsed.as_millis() as f64).max(0.0);
+                Some((remaining_ms * rate) as u32)
+            }
+            StopCondition::OrElse(a, b) => {
+                let bounds = (
+                    a.remaining_iterations_bound(iterations, elapsed),
+                    b.remaining_iterations_bound(iterations, elapsed),
+                );
+                match bounds {
+                    (Some(x), Some(y)) => Some(x.min(y)),
+                    (Some(x), None) | (None, Some(x)) => Some(x),
+                    (None, None) => None,
+                }
+            }
+        }
+    }
 }
 
 /// Represents a MCTS Tree. Owns all the nodes in the tree.
@@ -55,20 +206,81 @@ pub struct Mcts {
     arena: Vec<Node>,
     /// The index of the root `Node`.
     root_node_index: usize,
+    /// Seedable PRNG driving move shuffling and rollouts, so an identical search can be replayed.
+    rng: StdRng,
+    /// Move-selection policy used during rollouts.
+    rollout_policy: RolloutPolicy,
+    /// Tree-selection policy used by `select_best_child_uct`.
+    selection_policy: SelectionPolicy,
+    /// Exploration constant scaling the UCB exploration term.
+    c_param: f32,
+    /// Pooled `(wins, visits)` statistics shared by all nodes with the same board
+    /// position, so transpositions reinforce one another rather than accumulating
+    /// statistics independently.
+    transposition: HashMap<BoardKey, (f32, u32)>,
 }
 
 impl Mcts {
+    /// Creates a search rooted at `state` using a fixed default seed.
     pub fn new(state: Game) -> Self {
+        Self::new_seeded(state, DEFAULT_SEED)
+    }
+
+    /// Creates a search rooted at `state` whose RNG is seeded with `seed`.
+    /// Two searches built from the same `state` and `seed` explore an identical tree.
+    pub fn new_seeded(state: Game, seed: [u8; 32]) -> Self {
+        let mut rng = StdRng::from_seed(seed);
+
         let mut arena: Vec<Node> = Vec::new();
-        let node = Node::new(state, None);
+        let node = Node::new(state, None, &mut rng);
         arena.push(node);
 
         return Mcts {
             arena,
             root_node_index: 0,
+            rng,
+            rollout_policy: RolloutPolicy::Heuristic,
+            selection_policy: SelectionPolicy::Ucb1,
+            c_param: C_PARAM,
+            transposition: HashMap::new(),
         };
     }
 
+    /// Selects the tree-selection policy used by `select_best_child_uct`.
+    pub fn set_selection_policy(&mut self, policy: SelectionPolicy) {
+        self.selection_policy = policy;
+    }
+
+    /// Sets the exploration constant scaling the UCB exploration term, letting
+    /// callers tune the exploration/exploitation balance.
+    pub fn set_exploration_constant(&mut self, c_param: f32) {
+        self.c_param = c_param;
+    }
+
+    /// Transposition-table key for a position.
+    fn board_key(state: &Game) -> BoardKey {
+        (
+            state.black_pieces,
+            state.white_pieces,
+            state.player_to_move == Player::Black,
+        )
+    }
+
+    /// Pooled `(wins, visits)` for `state` across every transposed node, or
+    /// `(0.0, 0)` if the position has never been visited.
+    fn shared_stats(&self, state: &Game) -> (f32, u32) {
+        self.transposition
+            .get(&Self::board_key(state))
+            .copied()
+            .unwrap_or((0.0, 0))
+    }
+
+    /// Selects the rollout policy used by `simulate`. Use `RolloutPolicy::Uniform`
+    /// to fall back to blind random playouts for comparison.
+    pub fn set_rollout_policy(&mut self, policy: RolloutPolicy) {
+        self.rollout_policy = policy;
+    }
+
     /// Takes ownership of `node` and adds it to `self.arena`.
     /// # Arguments
     /// * `parent` - The index of the parent in `self.arena`.
@@ -76,12 +288,113 @@ impl Mcts {
     /// Returns the index of the newly added `Node`.
     fn add_node(&mut self, parent: usize, state: Game) -> usize {
         let index = self.arena.len();
-        let node = Node::new(state, Some(parent)); // root node does not have parent
+        let node = Node::new(state, Some(parent), &mut self.rng); // root node does not have parent
         self.arena.push(node);
 
         return index; // index of added node
     }
 
+    /// Reuses the subtree reached by playing `play` from the current root as the
+    /// new root, pruning every node unreachable from it so accumulated visit and
+    /// win statistics carry across the turn. When `play` is not an expanded child
+    /// of the current root, falls back to building a fresh single-node root from
+    /// the resulting state.
+    /// # Errors
+    /// Returns [`SearchError::IllegalMove`] and leaves the tree unchanged if
+    /// `play` is not legal for the current root. An illegal play never matches
+    /// an expanded child, so it is only validated on the fallback path.
+    pub fn advance_root(&mut self, play: Play) -> Result<(), SearchError> {
+        let root = self.get_node(self.root_node_index);
+
+        let child = root
+            .children
+            .iter()
+            .copied()
+            .find(|&child_index| self.get_node(child_index).state.previous_move == play);
+
+        match child {
+            Some(new_root) => self.rebuild_from(new_root),
+            None => {
+                let state = Self::advance_state(&self.arena[self.root_node_index].state, play)?;
+                let node = Node::new(state, None, &mut self.rng);
+                self.arena = vec![node];
+                self.root_node_index = 0;
+                // The whole tree was discarded, so none of the pooled stats are
+                // for positions reachable from the new root; keeping them around
+                // would let unrelated discarded subtrees inflate newly expanded
+                // children's inherited visit/win counts.
+                self.transposition.clear();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds `self.arena` so it only contains the subtree rooted at `new_root`,
+    /// which becomes index `0`. Parent and child indices are remapped into the
+    /// compacted arena, and `self.transposition` is pruned down to only the
+    /// positions that are still reachable, so discarded subtrees can no longer
+    /// leak stale pooled stats into nodes expanded after this call.
+    fn rebuild_from(&mut self, new_root: usize) {
+        use std::collections::VecDeque;
+
+        let old_arena = std::mem::take(&mut self.arena);
+
+        // Assign new indices by breadth-first traversal of the retained subtree.
+        let mut new_index_of: Vec<Option<usize>> = vec![None; old_arena.len()];
+        let mut order: Vec<usize> = Vec::new();
+        let mut queue: VecDeque<usize> = VecDeque::new();
+
+        new_index_of[new_root] = Some(0);
+        order.push(new_root);
+        queue.push_back(new_root);
+
+        while let Some(old) = queue.pop_front() {
+            for &child in &old_arena[old].children {
+                if new_index_of[child].is_none() {
+                    new_index_of[child] = Some(order.len());
+                    order.push(child);
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        // Rebuild the nodes in new-index order, remapping parent/child links.
+        let mut new_arena: Vec<Node> = Vec::with_capacity(order.len());
+        for (new_idx, &old) in order.iter().enumerate() {
+            let node = &old_arena[old];
+            let parent = if new_idx == 0 {
+                None // the retained subtree root no longer has a parent
+            } else {
+                node.parent.and_then(|p| new_index_of[p])
+            };
+            let children = node
+                .children
+                .iter()
+                .filter_map(|&c| new_index_of[c])
+                .collect();
+
+            new_arena.push(Node {
+                parent,
+                children,
+                unexpanded_moves: node.unexpanded_moves.clone(),
+                wins: node.wins,
+                visits: node.visits,
+                state: node.state.clone(),
+            });
+        }
+
+        let retained_keys: std::collections::HashSet<BoardKey> = new_arena
+            .iter()
+            .map(|node| Self::board_key(&node.state))
+            .collect();
+        self.transposition
+            .retain(|key, _| retained_keys.contains(key));
+
+        self.arena = new_arena;
+        self.root_node_index = 0;
+    }
+
     fn get_node(&self, index: usize) -> &Node {
         &self.arena[index]
     }
@@ -90,12 +403,13 @@ impl Mcts {
         &mut self.arena[index]
     }
 
-    /// Clones `state` and mutates the game with `play`.
-    fn advance_state(state: &Game, play: Play) -> Game {
+    /// Clones `state` and mutates the game with `play`, or returns
+    /// [`IllegalMove`] if `play` is not legal for `state`.
+    fn advance_state(state: &Game, play: Play) -> Result<Game, IllegalMove> {
         let mut tmp_state = state.clone();
-        tmp_state.make_play(play);
+        tmp_state.make_play(play)?;
 
-        return tmp_state;
+        return Ok(tmp_state);
     }
 
     /// Returns the best child of the node at `self.arena[index]` according to uct formula or `None` if no `children`.
@@ -104,11 +418,20 @@ impl Mcts {
         let mut best_score = f32::MIN;
 
         let node = self.get_node(index);
+        let (_, parent_visits) = self.shared_stats(&node.state);
+        let ln_parent = (parent_visits.max(1) as f32).ln();
 
         for child_index in &node.children {
             let child = self.get_node(*child_index);
-            let score: f32 = (child.wins / child.visits as f32)
-                + (C_PARAM * child.wins.log2().sqrt() / child.visits as f32);
+            // Read the pooled statistics so transpositions share their counts.
+            let (wins, visits) = self.shared_stats(&child.state);
+
+            // An unvisited child has infinite priority, so every child is tried once.
+            let score = if visits == 0 {
+                f32::INFINITY
+            } else {
+                self.uct_score(wins, visits, ln_parent)
+            };
 
             if score > best_score {
                 best_index = Some(*child_index);
@@ -119,6 +442,24 @@ impl Mcts {
         return best_index;
     }
 
+    /// UCB score of a child given its pooled `(wins, visits)` and `ln(parent visits)`,
+    /// using the configured `selection_policy` and exploration constant.
+    fn uct_score(&self, wins: f32, visits: u32, ln_parent: f32) -> f32 {
+        let visits = visits as f32;
+        let exploit = wins / visits;
+
+        match self.selection_policy {
+            SelectionPolicy::Ucb1 => exploit + self.c_param * (ln_parent / visits).sqrt(),
+            SelectionPolicy::Ucb1Tuned => {
+                // Rollout rewards are in {0, 1}, so the empirical variance is
+                // `mean - mean^2`; cap the bound at 1/4 as in the original paper.
+                let variance = exploit - exploit * exploit;
+                let variance_bound = variance + (2.0 * ln_parent / visits).sqrt();
+                exploit + self.c_param * (ln_parent / visits * variance_bound.min(0.25)).sqrt()
+            }
+        }
+    }
+
     /// ### Monte Carlo Tree Search - step 1.
     /// Returns the index of the selected node in `self.arena`.
     fn select(&self) -> usize {
@@ -141,39 +482,50 @@ impl Mcts {
 
     /// ### Monte Carlo Tree Search - step 2.
     /// Picks `self.children[self.unexpanded_index]` and expands the node. Pops a `Play` from `unexpanded_plays` and pushes the index of the added `Node` to `children`.
-    /// Returns the index of the new `Node`.
-    ///
-    /// # Panics
-    /// This method panics if there are no more moves left to expand for the specified node.
-    fn expand(&mut self, index: usize) -> usize {
+    /// Returns the index of the new `Node`, or [`SearchError::NoMovesToExpand`] if
+    /// there are no more moves left to expand for the specified node.
+    fn expand(&mut self, index: usize) -> Result<usize, SearchError> {
         let last_move = self.get_node_mut(index).unexpanded_moves.pop();
 
         if let Some(play) = last_move {
             let new_node_index = self.arena.len();
 
-            let new_state = Self::advance_state(&self.arena[index].state, play); // create new state from play
+            // `play` came from `unexpanded_moves`, which is populated from
+            // `generate_plays`, so it is always legal for this state.
+            let new_state = Self::advance_state(&self.arena[index].state, play)
+                .expect("play from unexpanded_moves is always legal");
             self.add_node(index, new_state); // create new Node
             self.get_node_mut(index).children.push(new_node_index);
 
-            return new_node_index;
+            // Inherit any statistics already gathered for this position via a
+            // different move order.
+            let (wins, visits) = self.shared_stats(&self.arena[new_node_index].state);
+            let new_node = self.get_node_mut(new_node_index);
+            new_node.wins = wins;
+            new_node.visits = visits;
+
+            Ok(new_node_index)
         } else {
-            panic!("No more moves left to expand.");
+            Err(SearchError::NoMovesToExpand)
         }
     }
 
     /// ### Monte Carlo Tree Search - step 3.
-    fn simulate(&self, index: usize) -> Player {
+    fn simulate(&mut self, index: usize) -> Player {
         let mut state = self.get_node(index).state.clone();
 
-        let mut rng = thread_rng();
-
         while state.game_state() == Player::InProgress {
             let plays = state.generate_plays();
-            // select random move
-            let rand_index = rng.gen_range(0, plays.len());
-            let play = plays[rand_index];
+            // select the rollout move according to the configured policy
+            let play = match self.rollout_policy {
+                RolloutPolicy::Uniform => plays[self.rng.gen_range(0, plays.len())],
+                RolloutPolicy::Heuristic => self.sample_heuristic(&state, &plays),
+            };
 
-            state.make_play(play);
+            // `play` came from `generate_plays`, so it is always legal for `state`.
+            state
+                .make_play(play)
+                .expect("play from generate_plays is always legal");
 
             if play == 64 {
                 // check if other player has a move, if false, return Player::Tie
@@ -196,27 +548,138 @@ impl Mcts {
         return state.game_state();
     }
 
+    /// Picks a rollout move from `plays` by softmax-weighted sampling over their
+    /// heuristic scores, so stronger-looking moves are played more often without
+    /// ever being forced. Falls back to the only play when there is no choice.
+    fn sample_heuristic(&mut self, state: &Game, plays: &[Play]) -> Play {
+        if plays.len() == 1 {
+            return plays[0];
+        }
+
+        let scores: Vec<f32> = plays.iter().map(|&p| Self::rollout_score(state, p)).collect();
+
+        // Softmax over the scores; subtract the max for numerical stability.
+        let max = scores.iter().cloned().fold(f32::MIN, f32::max);
+        let weights: Vec<f32> = scores.iter().map(|s| (s - max).exp()).collect();
+        let total: f32 = weights.iter().sum();
+
+        let mut target = self.rng.gen_range(0.0, total);
+        for (i, weight) in weights.iter().enumerate() {
+            target -= weight;
+            if target <= 0.0 {
+                return plays[i];
+            }
+        }
+
+        plays[plays.len() - 1]
+    }
+
+    /// Static evaluation of playing `play` from `state`, combining the positional
+    /// weight of the target square with a mobility term that favours moves leaving
+    /// the opponent fewer replies.
+    fn rollout_score(state: &Game, play: Play) -> f32 {
+        if play == 64 {
+            return 0.0; // the skip play has no position on the board
+        }
+
+        let positional = POSITION_WEIGHTS[play as usize] as f32;
+
+        let mut next = state.clone();
+        next.make_play(play)
+            .expect("play came from generate_plays and is always legal");
+        let replies = next.generate_plays();
+        // A lone skip play means the opponent is forced to pass — no real reply.
+        let opponent_replies = if replies.len() == 1 && replies[0] == 64 {
+            0.0
+        } else {
+            replies.len() as f32
+        };
+
+        // Keep the exponent in a sensible range for the softmax.
+        (positional - 5.0 * opponent_replies) / 20.0
+    }
+
     /// ### Monte Carlo Tree Search - step 4.
     fn backpropagate(&mut self, index: usize, winner: Player) {
-        let node = self.get_node_mut(index);
+        let key = Self::board_key(&self.get_node(index).state);
+        let win_delta = if self.get_node(index).state.player_to_move != winner {
+            1.0 // is current player
+        } else {
+            0.0
+        };
 
+        let node = self.get_node_mut(index);
         node.visits += 1;
+        node.wins += win_delta;
 
-        if node.state.player_to_move != winner {
-            // is current player
-            node.wins += 1.0;
-        }
+        // Update the pooled statistics so transposed nodes see the same counts.
+        let entry = self.transposition.entry(key).or_insert((0.0, 0));
+        entry.0 += win_delta;
+        entry.1 += 1;
 
-        if let Some(parent) = node.parent {
+        if let Some(parent) = self.get_node(index).parent {
             self.backpropagate(parent, winner); // backpropagate parent
         }
     }
 
-    /// Runs Monte Carlo Tree Search
-    /// # Arguments
-    /// * `time_budget` - the time budget for running the search in `ms`.
-    pub fn run_search(&mut self, time_budget: u128) -> MctsSearchResult {
-        use std::time::{Duration, Instant};
+    /// Pooled `(play, wins, visits)` for each expanded child of the root.
+    fn root_child_stats(&self) -> Vec<(Play, f32, u32)> {
+        let root_node = self.get_node(self.root_node_index);
+        root_node
+            .children
+            .iter()
+            .map(|&child_index| {
+                let child = self.get_node(child_index);
+                let (wins, visits) = self.shared_stats(&child.state);
+                (child.state.previous_move, wins, visits)
+            })
+            .collect()
+    }
+
+    /// Whether the most-visited root child already has so many more visits
+    /// than its closest rival that no rival could catch up even if every one
+    /// of the `remaining_budget` further playouts were spent on it alone.
+    /// Only meaningful once the root is fully expanded, since an unexpanded
+    /// move's true visit count isn't known yet.
+    fn root_decided(&self, remaining_budget: u32) -> bool {
+        let mut visits: Vec<u32> = self
+            .root_child_stats()
+            .into_iter()
+            .map(|(_, _, visits)| visits)
+            .collect();
+        visits.sort_unstable();
+
+        match visits.len() {
+            0 | 1 => false, // nothing to compare against
+            n => {
+                let leader = visits[n - 1];
+                let runner_up = visits[n - 2];
+                runner_up + remaining_budget <= leader
+            }
+        }
+    }
+
+    /// Runs Monte Carlo Tree Search until `stop` is satisfied, or earlier if
+    /// the leading root child becomes provably unreachable by any sibling
+    /// within the remaining budget.
+    /// # Errors
+    /// Returns [`SearchError::NoMovesToExpand`] if a node selected for expansion
+    /// unexpectedly has none, which should not happen for a non-terminal state.
+    pub fn run_search(&mut self, stop: StopCondition) -> Result<MctsSearchResult, SearchError> {
+        use std::time::Instant;
+
+        // Few enough empty squares remain that the exact solver is both fast and
+        // optimal; there is nothing for MCTS to add.
+        if crate::endgame::is_solvable(&self.get_node(self.root_node_index).state) {
+            return Ok(MctsSearchResult {
+                search_iterations: 0,
+                elapsed: Duration::default(),
+                best_play: crate::endgame::solve(&self.get_node(self.root_node_index).state),
+                best_play_visits: 0,
+                best_play_wins: 0.0,
+                best_play_win_rate: 0.0,
+            });
+        }
 
         let mut iterations_count: u32 = 0;
         let time_start = Instant::now();
@@ -228,43 +691,71 @@ impl Mcts {
                 let winner = self.simulate(node_index); // step 3
                 self.backpropagate(node_index, winner); // step 4
             } else {
-                let expanded_index = self.expand(node_index); // step 2
+                let expanded_index = self.expand(node_index)?; // step 2
                 let winner = self.simulate(expanded_index); // step 3
                 self.backpropagate(expanded_index, winner); // step 4
             }
 
             iterations_count += 1;
+            let elapsed = time_start.elapsed();
 
-            let duration: Duration = time_start.elapsed();
-            if duration.as_millis() > time_budget {
+            if stop.is_met(iterations_count, elapsed) {
                 break;
             }
+
+            let root_fully_expanded = self.get_node(self.root_node_index).is_fully_expanded();
+            if root_fully_expanded {
+                if let Some(remaining) = stop.remaining_iterations_bound(iterations_count, elapsed) {
+                    if self.root_decided(remaining) {
+                        break;
+                    }
+                }
+            }
         }
 
-        return MctsSearchResult {
-            search_iterations: iterations_count,
+        let (best_play, best_play_wins, best_play_visits) = self
+            .root_child_stats()
+            .into_iter()
+            .max_by_key(|&(_, _, visits)| visits)
+            .unwrap_or((0, 0.0, 0));
+        let best_play_win_rate = if best_play_visits > 0 {
+            best_play_wins / best_play_visits as f32
+        } else {
+            0.0
         };
+
+        return Ok(MctsSearchResult {
+            search_iterations: iterations_count,
+            elapsed: time_start.elapsed(),
+            best_play,
+            best_play_visits,
+            best_play_wins,
+            best_play_win_rate,
+        });
     }
 
-    pub fn best_play(&self) -> Play {
+    /// Returns the most-visited play from the root, or hands the decision to
+    /// the exact solver in the late endgame.
+    /// # Errors
+    /// Returns [`SearchError::RootNotFullyExpanded`] if `run_search` has not
+    /// been run long enough to expand every root child yet.
+    pub fn best_play(&self) -> Result<Play, SearchError> {
         let root_node = self.get_node(self.root_node_index);
 
-        if !root_node.is_fully_expanded() {
-            panic!("Root is not fully expanded.");
+        // Hand the decision to the exact solver in the late endgame.
+        if crate::endgame::is_solvable(&root_node.state) {
+            return Ok(crate::endgame::solve(&root_node.state));
         }
 
-        let mut best_visits: u32 = 0;
-        let mut best_play: Play = 0;
-
-        for child_index in &root_node.children {
-            let child = self.get_node(*child_index);
-
-            if child.visits > best_visits {
-                best_play = child.state.previous_move;
-                best_visits = child.visits;
-            }
+        if !root_node.is_fully_expanded() {
+            return Err(SearchError::RootNotFullyExpanded);
         }
 
-        return best_play;
+        Ok(self
+            .root_child_stats()
+            .into_iter()
+            .max_by_key(|&(_, _, visits)| visits)
+            .map(|(play, _, _)| play)
+            .unwrap_or(0))
     }
 }
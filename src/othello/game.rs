@@ -1,13 +1,16 @@
 use crate::othello::play::{new_play, Play};
+use std::fmt;
 
 /// Alias for `u64`. A `BitField` is used for black locations and another for white locations.
 type BitField = u64;
 
-/// Represents a player.
-#[derive(Debug, PartialEq)]
+/// Represents the current state of the game.
+#[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Player {
     Black,
     White,
+    InProgress,
+    Tie,
 }
 
 /// Represents the state of a cell
@@ -18,14 +21,31 @@ pub enum Cell {
     White,
 }
 
+/// Error returned when a requested `Play` is not legal for the side to move:
+/// out of range, its target square is already occupied, or it would not flip
+/// any opponent disks.
+#[derive(Debug, PartialEq, Eq)]
+pub struct IllegalMove;
+
+impl fmt::Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "illegal move")
+    }
+}
+
+impl std::error::Error for IllegalMove {}
+
 /// Represents an Othello game board.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Game {
     pub black_pieces: BitField,
     pub white_pieces: BitField,
 
     /// Next player to move
-    player_to_move: Player,
+    pub player_to_move: Player,
+    /// The play that produced this board, used by `Mcts::advance_root` to
+    /// match children against the move actually played.
+    pub previous_move: Play,
 }
 
 impl Game {
@@ -35,6 +55,7 @@ impl Game {
             black_pieces: (1 << new_play(3, 3)) | (1 << new_play(4, 4)),
             white_pieces: (1 << new_play(3, 4)) | (1 << new_play(4, 3)),
             player_to_move: Player::Black,
+            previous_move: 64,
         };
     }
 
@@ -70,7 +91,7 @@ impl Game {
     }
 
     /// Returns a vector of moves. Generates moves for the player in `self.player_to_move`.
-    fn generate_plays_bitfield(&self) -> BitField {
+    pub(crate) fn generate_plays_bitfield(&self) -> BitField {
         let my_disks: &BitField;
         let opponent_disks: &BitField;
         if self.player_to_move == Player::Black {
@@ -112,6 +133,10 @@ impl Game {
         return legal_moves;
     }
 
+    /// Returns a `Vec<Play>` of legal plays.
+    /// # Postcondition
+    /// The returned vector always has at least 1 play. If there are no plays
+    /// available, the method returns the "skip" play (represented by 64).
     pub fn generate_plays(&self) -> Vec<Play> {
         let mut bitfield: BitField = self.generate_plays_bitfield();
 
@@ -126,6 +151,10 @@ impl Game {
             index += 1;
         }
 
+        if v.is_empty() {
+            v.push(64); // "skip" play
+        }
+
         v
     }
 
@@ -134,9 +163,26 @@ impl Game {
         self.generate_plays_bitfield() != 0
     }
 
-    // pub fn is_valid_move(&self) {}
+    /// Returns whether `play` is legal for `self.player_to_move`: within range,
+    /// targeting an empty square, and flipping at least one opponent disk (or
+    /// `play == 64` as a legal "skip" when the side to move has no moves).
+    pub fn is_valid_play(&self, play: Play) -> bool {
+        if play == 64 {
+            return !self.has_valid_plays();
+        }
+        if play > 63 {
+            return false;
+        }
+
+        self.generate_plays_bitfield() & (1 << play) != 0
+    }
 
     /// Modifies game board and flips opponent disks.
+    ///
+    /// # Panics
+    /// Panics in debug builds if `play` is not legal for `self.player_to_move`.
+    /// Callers should validate `play` with [`Game::is_valid_play`] first, or go
+    /// through [`Game::play`] / [`Game::make_play`], which validate for you.
     fn resolve_play(&mut self, play: Play) {
         let my_disks: &mut BitField;
         let opponent_disks: &mut BitField;
@@ -150,10 +196,14 @@ impl Game {
 
         let mut x: u64;
 
-        let new_disk: u64 = 1 << play; // shift 1 to correct index
+        let new_disk: u64 = if play == 64 {
+            0 // "skip" play: the player passes and places no disk
+        } else {
+            1 << play // shift 1 to correct index
+        };
         let mut captured_disks: u64 = 0;
 
-        debug_assert!(play < 64, "Move must be within the board.");
+        debug_assert!(play < 65, "Move must be within the board."); // 64 is the "skip" play
         debug_assert!(
             *my_disks & *opponent_disks == 0,
             "Disk sets must be disjoint."
@@ -181,7 +231,10 @@ impl Game {
             captured_disks |= if bounding_disk != 0 { x } else { 0 }; // do nothing if bounding_disk == 0
         }
 
-        debug_assert!(captured_disks != 0, "A valid move must capture disks.");
+        debug_assert!(
+            play == 64 || captured_disks != 0,
+            "A valid move must capture disks."
+        );
 
         // mutate board with captured_disks
         *my_disks ^= captured_disks;
@@ -198,12 +251,30 @@ impl Game {
             (*my_disks & *opponent_disks) == 0,
             "The sets must still be disjoint"
         );
+
+        self.previous_move = play;
     }
 
-    /// Makes sure `play` is a valid `Play` and mutates the board.
-    pub fn make_play(&mut self, play: Play) {
-        // debug_assert!
+    /// Validates `play` and mutates the board in place.
+    /// # Errors
+    /// Returns [`IllegalMove`] and leaves `self` unchanged if `play` is not
+    /// legal for `self.player_to_move`.
+    pub fn make_play(&mut self, play: Play) -> Result<(), IllegalMove> {
+        if !self.is_valid_play(play) {
+            return Err(IllegalMove);
+        }
+
         self.resolve_play(play);
+        Ok(())
+    }
+
+    /// Validates `play` and returns the resulting board, or `None` if `play`
+    /// is not legal for `self.player_to_move`. Unlike `make_play`, `self` is
+    /// left untouched either way.
+    pub fn play(&self, play: Play) -> Option<Game> {
+        let mut next = self.clone();
+        next.make_play(play).ok()?;
+        Some(next)
     }
 
     /// Returns the `Cell` state with the specified `row` and `col`.
@@ -218,9 +289,40 @@ impl Game {
             return Cell::Empty;
         }
     }
+
+    /// Computes the game state: `Player::InProgress` while the side to move
+    /// (or its opponent) still has a legal play, otherwise the player with
+    /// more disks on the board, or `Player::Tie` if they are equal.
+    pub fn game_state(&self) -> Player {
+        if self.has_valid_plays() {
+            return Player::InProgress;
+        }
+
+        let opponent = Self {
+            player_to_move: if self.player_to_move == Player::Black {
+                Player::White
+            } else {
+                Player::Black
+            },
+            ..self.clone()
+        };
+        if opponent.has_valid_plays() {
+            return Player::InProgress;
+        }
+
+        let black_count = self.black_pieces.count_ones();
+        let white_count = self.white_pieces.count_ones();
+
+        if black_count > white_count {
+            Player::Black
+        } else if white_count > black_count {
+            Player::White
+        } else {
+            Player::Tie
+        }
+    }
 }
 
-use std::fmt;
 impl fmt::Display for Cell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {